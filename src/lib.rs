@@ -15,9 +15,85 @@
 use std::convert::TryFrom;
 
 use futures::future;
+use serde::de::DeserializeOwned;
 use warp::http::Method;
 use warp::{Buf, Filter};
 
+/// The name of the query parameter and form field used to carry the method
+/// override.
+const METHOD_FIELD: &str = "_method";
+
+/// The name of the header used to carry the method override.
+const METHOD_HEADER: &str = "X-HTTP-Method-Override";
+
+/// A rejection produced when a `_method` override is present in a request
+/// but does not match the method the filter was looking for.
+///
+/// Recover this with [`recover_method_override_mismatch`] to turn it into a
+/// proper `405 Method Not Allowed` response.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MethodOverrideMismatch {
+    /// The method that was found in the request's override.
+    pub attempted: Method,
+    /// The method the filter expected the override to be.
+    pub expected: Method,
+}
+
+impl warp::reject::Reject for MethodOverrideMismatch {}
+
+/// Recovers a [`MethodOverrideMismatch`] rejection into a `405 Method Not
+/// Allowed` response with an `Allow` header naming the expected method.
+///
+/// Intended to be used with [`Filter::recover`], after the preceding filter
+/// has been turned into a [`Reply`](warp::Reply) (e.g. with
+/// [`warp::reply`](fn@warp::reply)):
+///
+/// ```no_run
+/// use warp::Filter;
+/// use warp::http::Method;
+/// use warp_form_method::{form_method, recover_method_override_mismatch};
+///
+/// let route = form_method(Method::PUT)
+///     .map(warp::reply)
+///     .recover(recover_method_override_mismatch);
+/// ```
+pub async fn recover_method_override_mismatch(
+    rejection: warp::Rejection,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    match rejection.find::<MethodOverrideMismatch>() {
+        Some(mismatch) => {
+            let reply = warp::reply::with_status(
+                warp::reply(),
+                warp::http::StatusCode::METHOD_NOT_ALLOWED,
+            );
+            let reply = warp::reply::with_header(
+                reply,
+                warp::http::header::ALLOW,
+                mismatch.expected.as_str(),
+            );
+            Ok(reply)
+        }
+        None => Err(rejection),
+    }
+}
+
+/// Rejects with a [`MethodOverrideMismatch`] if `attempted` doesn't match
+/// `expected`, or with a generic rejection if no override was attempted at
+/// all.
+fn reject_unless_method_matches(
+    attempted: Option<Method>,
+    expected: Method,
+) -> Result<(), warp::Rejection> {
+    match attempted {
+        Some(attempted) if attempted == expected => Ok(()),
+        Some(attempted) => Err(warp::reject::custom(MethodOverrideMismatch {
+            attempted,
+            expected,
+        })),
+        None => Err(warp::reject()),
+    }
+}
+
 /// Returns a [`Filter`] that matches a request with the following criteria:
 /// - is a `POST` request
 /// - has a `Content-Type: application/x-www-form-urlencoded` header and body
@@ -32,49 +108,317 @@ pub fn form_method(method: Method) -> impl Filter<Extract = (), Error = warp::Re
         .and(is_form_content())
         .and(warp::body::aggregate())
         .map(parse_method_in_first_field)
-        .and_then(move |form_method| match form_method {
-            Some(form_method) if form_method == method => future::ok(()),
-            _ => future::err(warp::reject()),
+        .and_then(move |form_method| {
+            future::ready(reject_unless_method_matches(form_method, method.clone()))
         })
         .untuple_one()
 }
 
+/// Returns a [`Filter`] that verifies the `_method` override matches the
+/// specified HTTP method, the same way [`form_method`] does, and then
+/// deserializes the entire `application/x-www-form-urlencoded` body into
+/// `T`.
+///
+/// Because [`form_method`] only extracts `()`, a handler after it has no way
+/// to read the remaining form fields; the body has already been consumed by
+/// [`warp::body::aggregate`]. `form_method_with` lets callers get both the
+/// verified method and the deserialized form in one step, e.g.
+/// `form_method_with::<UpdateUser>(Method::PUT).and_then(handler)`.
+pub fn form_method_with<T>(
+    method: Method,
+) -> impl Filter<Extract = (T,), Error = warp::Rejection> + Clone
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    warp::post()
+        .and(is_form_content())
+        .and(warp::body::aggregate())
+        .and_then(move |body| future::ready(deserialize_if_method_matches(body, method.clone())))
+}
+
+/// Checks the `_method` override in the first field of `body` against
+/// `method`, and if it matches, deserializes `body` into `T`.
+fn deserialize_if_method_matches<T>(
+    mut body: impl Buf,
+    method: Method,
+) -> Result<T, warp::Rejection>
+where
+    T: DeserializeOwned,
+{
+    let mut bytes = vec![0; body.remaining()];
+    body.copy_to_slice(&mut bytes);
+
+    reject_unless_method_matches(parse_method_in_first_field(&bytes[..]), method)?;
+
+    serde_urlencoded::from_bytes(&bytes).map_err(|_| warp::reject())
+}
+
+/// Returns a [`Filter`] that matches a request whose `_method` query
+/// parameter is a valid HTTP method matching the specified method.
+///
+/// This allows clients that can't choose the request method or set custom
+/// headers (e.g. `<a>` links or simple `fetch` callers) to override the method by
+/// appending `?_method=DELETE` to the URL.
+pub fn query_method(method: Method) -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    warp::query::raw()
+        .or(warp::any().map(String::new))
+        .unify()
+        .and_then(move |query: String| {
+            future::ready(reject_unless_method_matches(
+                parse_method_in_query(&query),
+                method.clone(),
+            ))
+        })
+        .untuple_one()
+}
+
+/// Returns a [`Filter`] that matches a request whose `X-HTTP-Method-Override`
+/// header is a valid HTTP method matching the specified method.
+pub fn header_method(
+    method: Method,
+) -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    warp::header::optional::<String>(METHOD_HEADER)
+        .and_then(move |header_method: Option<String>| {
+            let header_method = header_method
+                .as_deref()
+                .and_then(|value| Method::try_from(value).ok());
+            future::ready(reject_unless_method_matches(header_method, method.clone()))
+        })
+        .untuple_one()
+}
+
+/// Returns a [`Filter`] that matches a request whose method override matches
+/// the specified method. The sources are tried in order — the
+/// `X-HTTP-Method-Override` header, the `_method` query parameter, then the
+/// `_method` form field — and the request matches if any one of them matches;
+/// an earlier source that names a different method does not stop a later one
+/// from matching.
+///
+/// This gives clients multiple escape hatches for specifying the override,
+/// so the crate is usable by JS `fetch` callers and link-style overrides, not
+/// only HTML `<form>` posts.
+pub fn method_override(
+    method: Method,
+) -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    header_method(method.clone())
+        .or(query_method(method.clone()))
+        .unify()
+        .or(form_method(method))
+        .unify()
+}
+
 fn is_form_content() -> impl Filter<Extract = (), Error = warp::Rejection> + Copy {
     warp::header::exact_ignore_case("Content-Type", "application/x-www-form-urlencoded")
 }
 
-/// The minimum length of the `_method` field.
-const MIN_LEN: usize = "_method=GET".len();
+/// Returns a [`Filter`] that matches a request with the following criteria,
+/// extracting the remaining [`FormData`](warp::multipart::FormData) stream
+/// on success:
+///
+/// - has a `Content-Type: multipart/form-data` header and body
+/// - the first part in the body has the name `_method` and a valid HTTP
+///   method as its contents
+/// - the value of the `_method` part matches the specified HTTP method
+///
+/// HTML forms that upload files must use `multipart/form-data`, so this
+/// filter lets such forms take part in the method override, too. It only
+/// reads the leading part needed to make its decision, mirroring how warp's
+/// own [`multipart::form`](warp::multipart::form) streams parts, and extracts
+/// the rest of the stream so a downstream handler can keep reading the
+/// remaining parts instead of re-extracting (and failing to re-extract) the
+/// already-consumed body.
+pub fn multipart_form_method(
+    method: Method,
+) -> impl Filter<Extract = (warp::multipart::FormData,), Error = warp::Rejection> + Clone {
+    is_multipart_content()
+        .and(warp::multipart::form())
+        .and_then(move |mut form: warp::multipart::FormData| {
+            let method = method.clone();
+            async move {
+                use futures::StreamExt;
+
+                let part = match form.next().await {
+                    Some(Ok(part)) => part,
+                    _ => return Err(warp::reject()),
+                };
+
+                if part.name() != METHOD_FIELD {
+                    return Err(warp::reject());
+                }
+
+                reject_unless_method_matches(part_to_method(part).await, method)?;
+
+                Ok(form)
+            }
+        })
+}
+
+fn is_multipart_content() -> impl Filter<Extract = (), Error = warp::Rejection> + Copy {
+    warp::header::value("Content-Type")
+        .and_then(|content_type: warp::http::HeaderValue| async move {
+            match content_type.to_str() {
+                Ok(content_type) if content_type.starts_with("multipart/form-data") => Ok(()),
+                _ => Err(warp::reject()),
+            }
+        })
+        .untuple_one()
+}
+
+/// Reads a multipart [`Part`](warp::multipart::Part)'s body to completion and
+/// attempts to parse it as an HTTP method.
+async fn part_to_method(part: warp::multipart::Part) -> Option<Method> {
+    use futures::TryStreamExt;
+
+    let mut bytes = Vec::new();
+    let mut stream = part.stream();
+    while let Some(buf) = stream.try_next().await.ok()? {
+        bytes.extend_from_slice(buf.chunk());
+    }
+
+    let value = std::str::from_utf8(&bytes).ok()?;
+    Method::try_from(value).ok()
+}
+
+/// Attempts to parse a `_method` parameter containing an HTTP method out of a
+/// raw query string.
+fn parse_method_in_query(query: &str) -> Option<Method> {
+    query.split('&').find_map(|pair| {
+        let mut parts = pair.splitn(2, '=');
+        match (parts.next(), parts.next()) {
+            (Some(METHOD_FIELD), Some(value)) => Method::try_from(value).ok(),
+            _ => None,
+        }
+    })
+}
 
-/// The maximum length of the `_method` field.
-const MAX_LEN: usize = "_method=DELETE".len();
+/// The default maximum length, in bytes, of the first field's `name=value`
+/// pair that will be peeked when looking for a `_method` override.
+///
+/// This is generous enough to accommodate long registered and custom HTTP
+/// method names (e.g. `PROPFIND`, `MKCALENDAR`, `VERSION-CONTROL`) while
+/// still bounding how much of the body is read.
+const DEFAULT_MAX_FIELD_LEN: usize = 64;
 
 /// Attempts to parse a `_method` field containing an HTTP method as the
 /// **first** field in an `application/x-www-form-urlencoded` body.
 ///
 /// If the `_method` field is not present, not the first field, or contains a
 /// value that can not be parsed as an HTTP method this will return [`None`].
-fn parse_method_in_first_field(mut body: impl Buf) -> Option<Method> {
-    if body.remaining() < MIN_LEN {
-        return None;
-    }
+fn parse_method_in_first_field(body: impl Buf) -> Option<Method> {
+    parse_method_in_first_field_with_max_len(body, DEFAULT_MAX_FIELD_LEN)
+}
 
-    let mut peek_buffer = vec![0; std::cmp::min(body.remaining(), MAX_LEN)];
+/// Like [`parse_method_in_first_field`], but with a configurable peek window
+/// instead of [`DEFAULT_MAX_FIELD_LEN`].
+///
+/// Scans up to the first `&` (or the end of the peeked region, whichever
+/// comes first) and validates the value against
+/// [`Method::try_from`](std::convert::TryFrom), which already accepts any
+/// registered or custom method name, instead of a fixed window derived from
+/// two built-in verbs.
+fn parse_method_in_first_field_with_max_len(
+    mut body: impl Buf,
+    max_field_len: usize,
+) -> Option<Method> {
+    let peek_len = std::cmp::min(body.remaining(), max_field_len);
+    let mut peek_buffer = vec![0; peek_len];
     body.copy_to_slice(&mut peek_buffer);
 
-    let mut parts = std::str::from_utf8(&peek_buffer)
-        .ok()?
-        .split(|c| c == '=' || c == '&')
-        .take(2);
+    let field = std::str::from_utf8(&peek_buffer).ok()?.split('&').next()?;
 
-    let name = parts.next();
-    let value = parts.next();
-    match (name, value) {
+    let mut parts = field.splitn(2, '=');
+    match (parts.next(), parts.next()) {
         (Some("_method"), Some(value)) => Method::try_from(value).ok(),
         _ => None,
     }
 }
 
+/// The default maximum number of bytes of the body that
+/// [`form_method_anywhere`] will read into memory while searching for a
+/// `_method` field, to guard against memory exhaustion from an unbounded
+/// request body.
+const DEFAULT_MAX_BODY_LEN: u64 = 16 * 1024;
+
+/// Returns a [`Filter`] that matches a request with the following criteria:
+/// - is a `POST` request
+/// - has a `Content-Type: application/x-www-form-urlencoded` header and body
+/// - **any** field in the form has the name `_method` and a valid HTTP
+///   method as the value
+/// - the value of the `_method` field matches the specified HTTP method
+///
+/// Unlike [`form_method`], this does not require `_method` to be the first
+/// field, at the cost of aggregating and scanning the entire body. The body
+/// is capped at [`DEFAULT_MAX_BODY_LEN`] bytes; use
+/// [`form_method_anywhere_with_max_len`] to configure this.
+pub fn form_method_anywhere(
+    method: Method,
+) -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    form_method_anywhere_with_max_len(method, DEFAULT_MAX_BODY_LEN)
+}
+
+/// Like [`form_method_anywhere`], but with a configurable maximum body size
+/// instead of [`DEFAULT_MAX_BODY_LEN`].
+pub fn form_method_anywhere_with_max_len(
+    method: Method,
+    max_body_len: u64,
+) -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    warp::post()
+        .and(is_form_content())
+        .and(warp::body::content_length_limit(max_body_len))
+        .and(warp::body::aggregate())
+        .map(parse_method_anywhere)
+        .and_then(move |form_method| {
+            future::ready(reject_unless_method_matches(form_method, method.clone()))
+        })
+        .untuple_one()
+}
+
+/// Attempts to parse a `_method` field containing an HTTP method out of
+/// **any** field in an `application/x-www-form-urlencoded` body, aggregating
+/// the entire body and URL-decoding the value before parsing it.
+///
+/// If no `_method` field is present, or its value can not be URL-decoded or
+/// parsed as an HTTP method, this will return [`None`].
+fn parse_method_anywhere(mut body: impl Buf) -> Option<Method> {
+    let mut bytes = vec![0; body.remaining()];
+    body.copy_to_slice(&mut bytes);
+    let body = std::str::from_utf8(&bytes).ok()?;
+
+    body.split('&').find_map(|pair| {
+        let mut parts = pair.splitn(2, '=');
+        match (parts.next(), parts.next()) {
+            (Some("_method"), Some(value)) => {
+                let value = percent_decode(value)?;
+                Method::try_from(value.as_str()).ok()
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Decodes a `application/x-www-form-urlencoded` value, turning `+` into a
+/// space and `%XX` into the byte it represents.
+fn percent_decode(value: &str) -> Option<String> {
+    let mut decoded = Vec::with_capacity(value.len());
+    let mut bytes = value.bytes();
+
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = bytes.by_ref().take(2).collect();
+                if hex.len() != 2 {
+                    return None;
+                }
+                decoded.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+            }
+            byte => decoded.push(byte),
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,6 +493,49 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn it_matches_with_patch_method_in_first_field() {
+        let filter = form_method(Method::PATCH);
+
+        assert!(
+            warp::test::request()
+                .method("POST")
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .body("_method=PATCH&first_name=john")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_matches_with_options_method_in_first_field() {
+        let filter = form_method(Method::OPTIONS);
+
+        assert!(
+            warp::test::request()
+                .method("POST")
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .body("_method=OPTIONS&first_name=john")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_matches_with_a_long_custom_method_in_first_field() {
+        let custom_method = Method::from_bytes(b"VERSION-CONTROL").unwrap();
+        let filter = form_method(custom_method);
+
+        assert!(
+            warp::test::request()
+                .method("POST")
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .body("_method=VERSION-CONTROL&first_name=john")
+                .matches(&filter)
+                .await
+        )
+    }
+
     #[tokio::test]
     async fn it_rejects_with_post_method_form_content_and_matching_method_not_in_first_field() {
         let filter = form_method(Method::PUT);
@@ -196,4 +583,252 @@ mod tests {
 
         assert!(!warp::test::request().method("POST").matches(&filter).await)
     }
+
+    fn multipart_method_body(boundary: &str, method: &str) -> String {
+        format!(
+            "--{boundary}\r\n\
+             Content-Disposition: form-data; name=\"_method\"\r\n\
+             \r\n\
+             {method}\r\n\
+             --{boundary}--\r\n"
+        )
+    }
+
+    #[tokio::test]
+    async fn it_matches_multipart_form_method_with_matching_put_method_in_first_part() {
+        let filter = multipart_form_method(Method::PUT);
+
+        assert!(
+            warp::test::request()
+                .header(
+                    "Content-Type",
+                    "multipart/form-data; boundary=boundary",
+                )
+                .body(multipart_method_body("boundary", "PUT"))
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_rejects_multipart_form_method_with_a_different_method_in_first_part() {
+        let filter = multipart_form_method(Method::PUT);
+
+        assert!(
+            !warp::test::request()
+                .header(
+                    "Content-Type",
+                    "multipart/form-data; boundary=boundary",
+                )
+                .body(multipart_method_body("boundary", "DELETE"))
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_matches_form_method_anywhere_with_method_not_in_first_field() {
+        let filter = form_method_anywhere(Method::PUT);
+
+        assert!(
+            warp::test::request()
+                .method("POST")
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .body("first_name=john&_method=PUT")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_matches_form_method_anywhere_with_a_url_encoded_value() {
+        let filter = form_method_anywhere(Method::PUT);
+
+        assert!(
+            warp::test::request()
+                .method("POST")
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .body("first_name=john&_method=PU%54")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_rejects_form_method_anywhere_with_no_method_field() {
+        let filter = form_method_anywhere(Method::PUT);
+
+        assert!(
+            !warp::test::request()
+                .method("POST")
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .body("first_name=john")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct UpdateUser {
+        first_name: String,
+    }
+
+    #[tokio::test]
+    async fn it_matches_form_method_with_and_deserializes_the_form() {
+        let filter = form_method_with::<UpdateUser>(Method::PUT);
+
+        let user = warp::test::request()
+            .method("POST")
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body("_method=PUT&first_name=john")
+            .filter(&filter)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            user,
+            UpdateUser {
+                first_name: "john".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_rejects_form_method_with_and_a_different_method_in_first_field() {
+        let filter = form_method_with::<UpdateUser>(Method::PUT);
+
+        assert!(
+            !warp::test::request()
+                .method("POST")
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .body("_method=DELETE&first_name=john")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_recovers_a_method_mismatch_into_a_405_with_an_allow_header() {
+        let filter = form_method(Method::PUT)
+            .map(warp::reply)
+            .recover(recover_method_override_mismatch);
+
+        let response = warp::test::request()
+            .method("POST")
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body("_method=DELETE&first_name=john")
+            .reply(&filter)
+            .await;
+
+        assert_eq!(response.status(), warp::http::StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(response.headers().get("Allow").unwrap(), "PUT");
+    }
+
+    #[tokio::test]
+    async fn it_matches_query_method_with_matching_method_in_query_string() {
+        let filter = query_method(Method::DELETE);
+
+        assert!(
+            warp::test::request()
+                .path("/?_method=DELETE")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_rejects_query_method_with_a_different_method_in_query_string() {
+        let filter = query_method(Method::DELETE);
+
+        assert!(
+            !warp::test::request()
+                .path("/?_method=PUT")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_rejects_query_method_with_no_query_string() {
+        let filter = query_method(Method::DELETE);
+
+        assert!(!warp::test::request().path("/").matches(&filter).await)
+    }
+
+    #[tokio::test]
+    async fn it_matches_header_method_with_matching_method_in_header() {
+        let filter = header_method(Method::DELETE);
+
+        assert!(
+            warp::test::request()
+                .header("X-HTTP-Method-Override", "DELETE")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_rejects_header_method_with_a_different_method_in_header() {
+        let filter = header_method(Method::DELETE);
+
+        assert!(
+            !warp::test::request()
+                .header("X-HTTP-Method-Override", "PUT")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_rejects_header_method_with_no_header() {
+        let filter = header_method(Method::DELETE);
+
+        assert!(!warp::test::request().matches(&filter).await)
+    }
+
+    #[tokio::test]
+    async fn it_matches_method_override_via_the_header_when_header_and_query_disagree() {
+        let filter = method_override(Method::DELETE);
+
+        assert!(
+            warp::test::request()
+                .path("/?_method=PUT")
+                .header("X-HTTP-Method-Override", "DELETE")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_matches_method_override_via_the_query_when_no_header_is_present() {
+        let filter = method_override(Method::DELETE);
+
+        assert!(
+            warp::test::request()
+                .path("/?_method=DELETE")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_matches_method_override_via_the_form_body_when_no_header_or_query_is_present() {
+        let filter = method_override(Method::DELETE);
+
+        assert!(
+            warp::test::request()
+                .method("POST")
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .body("_method=DELETE&first_name=john")
+                .matches(&filter)
+                .await
+        )
+    }
+
+    #[tokio::test]
+    async fn it_rejects_method_override_with_no_header_query_or_form_field() {
+        let filter = method_override(Method::DELETE);
+
+        assert!(!warp::test::request().method("POST").matches(&filter).await)
+    }
 }